@@ -0,0 +1,258 @@
+use serde::Serialize;
+use swc_common::SourceMap;
+use swc_ecma_ast::Module;
+use swc_ecma_visit::Visit;
+
+use crate::imports::{ImportCollector, ImportSpecifierType};
+use crate::policy::{classify, ImportStatus, ImportsNotUsedAsValues};
+use crate::preserve::PreserveAllowlist;
+use crate::scope::IdentifierCollector;
+
+/// Which flavor of specifier an unused binding came from.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SpecifierKind {
+    Named,
+    Default,
+    Namespace,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnusedSpecifier {
+    pub local: String,
+    pub imported: String,
+    pub kind: SpecifierKind,
+    pub position: Position,
+}
+
+/// One unused-import finding, scoped to the `ImportDecl` it came from.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnusedImportDiagnostic {
+    pub module: String,
+    pub position: Position,
+    /// True when every specifier on this declaration is unused, so the
+    /// whole `import` statement can be deleted; false when only some of
+    /// them are.
+    pub whole_import_unused: bool,
+    pub specifiers: Vec<UnusedSpecifier>,
+}
+
+/// One finding under [`ImportsNotUsedAsValues::Error`]: a value import
+/// that's only ever referenced in type position and should be rewritten
+/// as `import type { Foo }` (or `import { type Foo }`).
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeOnlyImportDiagnostic {
+    pub module: String,
+    pub position: Position,
+    pub specifiers: Vec<UnusedSpecifier>,
+}
+
+/// Everything [`collect_diagnostics`] found, split by what should happen to
+/// each finding: `unused` imports can be deleted outright, while
+/// `should_be_type_only` imports are still referenced and must be kept, but
+/// only ever as a type.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportDiagnostics {
+    pub unused: Vec<UnusedImportDiagnostic>,
+    pub should_be_type_only: Vec<TypeOnlyImportDiagnostic>,
+}
+
+/// Walks `module`, classifying every import against `policy`/`allowlist`,
+/// and returns span-backed diagnostics for anything unused or (under
+/// [`ImportsNotUsedAsValues::Error`]) anything that should be type-only.
+///
+/// See [`crate::policy::classify`] for what `erases_types` controls.
+pub fn collect_diagnostics(
+    module: &Module,
+    cm: &SourceMap,
+    policy: ImportsNotUsedAsValues,
+    allowlist: &PreserveAllowlist,
+    erases_types: bool,
+) -> ImportDiagnostics {
+    let mut import_collector = ImportCollector::new();
+    import_collector.visit_module(module);
+
+    let mut identifier_collector = IdentifierCollector::new();
+    identifier_collector.visit_module(module);
+
+    let mut unused = Vec::new();
+    let mut should_be_type_only = Vec::new();
+
+    for decl in &import_collector.decls {
+        if allowlist.is_preserved(&decl.source) {
+            continue;
+        }
+
+        let mut unused_specifiers = Vec::new();
+        let mut type_only_specifiers = Vec::new();
+        for local in &decl.locals {
+            let binding = match import_collector.imports.get(local) {
+                Some(binding) => binding,
+                None => continue,
+            };
+
+            let value_used = identifier_collector.value_identifiers().contains(local);
+            let type_used = identifier_collector.type_identifiers().contains(local);
+            let status = classify(binding.type_only, value_used, type_used, policy, erases_types);
+            if status == ImportStatus::Used {
+                continue;
+            }
+
+            let (imported, kind) = match &binding.kind {
+                ImportSpecifierType::Named(imported) => (imported.clone(), SpecifierKind::Named),
+                ImportSpecifierType::Default(_) => (local.clone(), SpecifierKind::Default),
+                ImportSpecifierType::Namespace(_) => (local.clone(), SpecifierKind::Namespace),
+            };
+            let loc = cm.lookup_char_pos(binding.span.lo);
+            let specifier = UnusedSpecifier {
+                local: local.clone(),
+                imported,
+                kind,
+                position: Position {
+                    line: loc.line,
+                    column: loc.col_display,
+                },
+            };
+
+            match status {
+                ImportStatus::Unused => unused_specifiers.push(specifier),
+                ImportStatus::ShouldBeTypeOnly => type_only_specifiers.push(specifier),
+                ImportStatus::Used => unreachable!("filtered out above"),
+            }
+        }
+
+        if !unused_specifiers.is_empty() {
+            let whole_import_unused = unused_specifiers.len() == decl.locals.len();
+            let loc = cm.lookup_char_pos(decl.span.lo);
+            unused.push(UnusedImportDiagnostic {
+                module: decl.source.clone(),
+                position: Position {
+                    line: loc.line,
+                    column: loc.col_display,
+                },
+                whole_import_unused,
+                specifiers: unused_specifiers,
+            });
+        }
+
+        if !type_only_specifiers.is_empty() {
+            let loc = cm.lookup_char_pos(decl.span.lo);
+            should_be_type_only.push(TypeOnlyImportDiagnostic {
+                module: decl.source.clone(),
+                position: Position {
+                    line: loc.line,
+                    column: loc.col_display,
+                },
+                specifiers: type_only_specifiers,
+            });
+        }
+    }
+
+    ImportDiagnostics {
+        unused,
+        should_be_type_only,
+    }
+}
+
+/// Renders `diagnostics` as machine-readable JSON, for editors/CI to
+/// consume directly.
+pub fn to_json(diagnostics: &ImportDiagnostics) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(diagnostics)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_js;
+
+    #[test]
+    fn whole_import_unused_when_every_specifier_is() {
+        let (cm, module) = parse_js("import { a, b } from 'mod';");
+        let diagnostics = collect_diagnostics(
+            &module,
+            &cm,
+            ImportsNotUsedAsValues::Remove,
+            &PreserveAllowlist::default(),
+            false,
+        );
+        assert_eq!(diagnostics.unused.len(), 1);
+        assert!(diagnostics.unused[0].whole_import_unused);
+        assert_eq!(diagnostics.unused[0].specifiers.len(), 2);
+    }
+
+    #[test]
+    fn whole_import_unused_false_when_only_some_specifiers_are() {
+        let (cm, module) = parse_js(
+            r#"
+            import { a, b } from 'mod';
+            console.log(a);
+            "#,
+        );
+        let diagnostics = collect_diagnostics(
+            &module,
+            &cm,
+            ImportsNotUsedAsValues::Remove,
+            &PreserveAllowlist::default(),
+            false,
+        );
+        assert_eq!(diagnostics.unused.len(), 1);
+        assert!(!diagnostics.unused[0].whole_import_unused);
+        assert_eq!(diagnostics.unused[0].specifiers[0].local, "b");
+    }
+
+    #[test]
+    fn to_json_renders_camel_case_fields_and_the_unused_should_be_type_only_split() {
+        let (cm, module) = parse_js(
+            r#"
+            import { a } from 'mod';
+            import { Foo } from 'types';
+            let x: Foo;
+            "#,
+        );
+        let diagnostics = collect_diagnostics(
+            &module,
+            &cm,
+            ImportsNotUsedAsValues::Error,
+            &PreserveAllowlist::default(),
+            false,
+        );
+        let json = to_json(&diagnostics).expect("diagnostics should always serialize");
+        let parsed: serde_json::Value = serde_json::from_str(&json).expect("valid JSON");
+
+        assert_eq!(parsed["unused"][0]["module"], "mod");
+        assert_eq!(parsed["unused"][0]["wholeImportUnused"], true);
+        assert_eq!(parsed["unused"][0]["specifiers"][0]["local"], "a");
+        assert_eq!(parsed["shouldBeTypeOnly"][0]["module"], "types");
+        assert_eq!(parsed["shouldBeTypeOnly"][0]["specifiers"][0]["local"], "Foo");
+    }
+
+    #[test]
+    fn should_be_type_only_surfaced_under_error_policy() {
+        let (cm, module) = parse_js(
+            r#"
+            import { Foo } from 'mod';
+            let x: Foo;
+            "#,
+        );
+        let diagnostics = collect_diagnostics(
+            &module,
+            &cm,
+            ImportsNotUsedAsValues::Error,
+            &PreserveAllowlist::default(),
+            false,
+        );
+        assert!(diagnostics.unused.is_empty());
+        assert_eq!(diagnostics.should_be_type_only.len(), 1);
+        assert_eq!(diagnostics.should_be_type_only[0].specifiers[0].local, "Foo");
+    }
+}
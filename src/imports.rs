@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use swc_common::Span;
+use swc_ecma_ast::{ImportDecl, ImportSpecifier, ModuleExportName};
+use swc_ecma_visit::Visit;
+
+/// Which flavor of specifier introduced a given local binding.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ImportSpecifierType {
+    Named(String),     // import { foo } from 'module'
+    Default(String),   // import foo from 'module'
+    Namespace(String), // import * as foo from 'module'
+}
+
+/// Everything the remover/diagnostics need to know about one imported
+/// local binding.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ImportBinding {
+    pub kind: ImportSpecifierType,
+    /// The module path the binding came from, e.g. `"date-fns"`.
+    pub source: String,
+    /// True when the binding can only ever be erased at runtime: either the
+    /// whole declaration is `import type ...`, or this specifier is
+    /// individually marked `type` (`import { type Foo }`).
+    pub type_only: bool,
+    /// Span of the specifier itself, for diagnostics.
+    pub span: Span,
+}
+
+/// One `ImportDecl`, recorded so diagnostics can report positions and group
+/// specifiers back by the statement they came from.
+#[derive(Debug)]
+pub struct ImportDeclInfo {
+    pub span: Span,
+    pub source: String,
+    pub locals: Vec<String>,
+}
+
+/// Struct to hold import information
+pub struct ImportCollector {
+    pub imports: HashMap<String, ImportBinding>,
+    /// Module paths pulled in only for their side effects, e.g.
+    /// `import './styles.css'`. These have no local bindings to report as
+    /// used or unused, but must never be treated as dead code.
+    pub side_effect_imports: Vec<String>,
+    /// One entry per `ImportDecl` that declares at least one specifier.
+    pub decls: Vec<ImportDeclInfo>,
+}
+
+impl ImportCollector {
+    pub fn new() -> Self {
+        Self {
+            imports: HashMap::new(),
+            side_effect_imports: Vec::new(),
+            decls: Vec::new(),
+        }
+    }
+}
+
+impl Default for ImportCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Visit for ImportCollector {
+    fn visit_import_decl(&mut self, import_decl: &ImportDecl) {
+        let source = import_decl.src.value.to_string_lossy().into_owned();
+
+        if import_decl.specifiers.is_empty() {
+            self.side_effect_imports.push(source);
+            return;
+        }
+
+        let mut locals = Vec::with_capacity(import_decl.specifiers.len());
+
+        for specifier in &import_decl.specifiers {
+            match specifier {
+                ImportSpecifier::Named(named) => {
+                    let imported = match &named.imported {
+                        Some(ModuleExportName::Ident(id)) => id.sym.to_string(),
+                        Some(ModuleExportName::Str(s)) => s.value.to_string_lossy().into_owned(),
+                        None => named.local.sym.to_string(),
+                    };
+                    let local = named.local.sym.to_string();
+                    locals.push(local.clone());
+                    self.imports.insert(
+                        local,
+                        ImportBinding {
+                            kind: ImportSpecifierType::Named(imported),
+                            source: source.clone(),
+                            type_only: import_decl.type_only || named.is_type_only,
+                            span: named.span,
+                        },
+                    );
+                }
+                ImportSpecifier::Default(default) => {
+                    let local = default.local.sym.to_string();
+                    locals.push(local.clone());
+                    self.imports.insert(
+                        local.clone(),
+                        ImportBinding {
+                            kind: ImportSpecifierType::Default(local),
+                            source: source.clone(),
+                            type_only: import_decl.type_only,
+                            span: default.span,
+                        },
+                    );
+                }
+                ImportSpecifier::Namespace(ns) => {
+                    let local = ns.local.sym.to_string();
+                    locals.push(local.clone());
+                    self.imports.insert(
+                        local.clone(),
+                        ImportBinding {
+                            kind: ImportSpecifierType::Namespace(local),
+                            source: source.clone(),
+                            type_only: import_decl.type_only,
+                            span: ns.span,
+                        },
+                    );
+                }
+            }
+        }
+
+        self.decls.push(ImportDeclInfo {
+            span: import_decl.span,
+            source,
+            locals,
+        });
+    }
+}
@@ -0,0 +1,83 @@
+use swc_common::{input::StringInput, sync::Lrc, FileName, SourceMap};
+use swc_ecma_ast::Module;
+use swc_ecma_parser::{lexer::Lexer, Parser, Syntax};
+use swc_ecma_visit::Visit;
+
+pub mod diagnostics;
+pub mod imports;
+pub mod plugin;
+pub mod policy;
+pub mod preserve;
+pub mod remover;
+pub mod scope;
+
+use imports::ImportCollector;
+use policy::{classify, ImportStatus, ImportsNotUsedAsValues};
+use preserve::PreserveAllowlist;
+use scope::IdentifierCollector;
+
+/// Parses `code` with the given `Syntax`, returning the `SourceMap` it was
+/// parsed against (needed later for codegen/diagnostics) alongside the
+/// resulting `Module`.
+pub fn parse_js_with_syntax(code: &str, syntax: Syntax) -> (Lrc<SourceMap>, Module) {
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(Lrc::new(FileName::Custom("file.js".into())), code.to_string());
+
+    let lexer = Lexer::new(syntax, Default::default(), StringInput::from(&*fm), None);
+
+    let mut parser = Parser::new_from(lexer);
+    let module = parser.parse_module().expect("Failed to parse module");
+    (cm, module)
+}
+
+/// Convenience wrapper around [`parse_js_with_syntax`] for standalone (CLI)
+/// use, where there's no host toolchain to read TSX/decorator flags from.
+/// Callers that know the source's dialect should call `parse_js_with_syntax`
+/// with their own `Syntax` instead; the wasm plugin ([`crate::plugin`])
+/// doesn't parse at all, since the host hands it an already-parsed `Program`.
+pub fn parse_js(code: &str) -> (Lrc<SourceMap>, Module) {
+    parse_js_with_syntax(
+        code,
+        Syntax::Typescript(swc_ecma_parser::TsSyntax {
+            tsx: true,
+            decorators: true,
+            ..Default::default()
+        }),
+    )
+}
+
+/// Finds the local names of imports that are unused under `policy`, after
+/// removing anything `allowlist` says must always be preserved.
+///
+/// See [`policy::classify`] for what `erases_types` controls.
+pub fn find_unused_imports(
+    module: &Module,
+    policy: ImportsNotUsedAsValues,
+    allowlist: &PreserveAllowlist,
+    erases_types: bool,
+) -> Vec<String> {
+    // Collect imports
+    let mut import_collector = ImportCollector::new();
+    import_collector.visit_module(module);
+
+    // Collect used identifiers, split by value vs. type position
+    let mut identifier_collector = IdentifierCollector::new();
+    identifier_collector.visit_module(module);
+
+    // Determine unused imports
+    let mut unused = Vec::new();
+    for (local, binding) in &import_collector.imports {
+        if allowlist.is_preserved(&binding.source) {
+            continue;
+        }
+
+        let value_used = identifier_collector.value_identifiers().contains(local);
+        let type_used = identifier_collector.type_identifiers().contains(local);
+        let status = classify(binding.type_only, value_used, type_used, policy, erases_types);
+        if status == ImportStatus::Unused {
+            unused.push(local.clone());
+        }
+    }
+
+    unused
+}
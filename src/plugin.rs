@@ -0,0 +1,175 @@
+use std::collections::HashSet;
+
+use serde::Deserialize;
+use swc_common::errors::HANDLER;
+use swc_core::plugin::plugin_transform;
+use swc_core::plugin::proxies::TransformPluginProgramMetadata;
+use swc_ecma_ast::Program;
+use swc_ecma_visit::{VisitMutWith, VisitWith};
+
+use crate::imports::ImportCollector;
+use crate::policy::{classify, ImportStatus, ImportsNotUsedAsValues};
+use crate::preserve::{PreserveAllowlist, PreserveRule};
+use crate::remover::unused_import_remover;
+use crate::scope::IdentifierCollector;
+
+/// Whether the plugin rewrites the module in place or only reports what it
+/// would remove (useful for editors/CI that want diagnostics, not a fix).
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum Mode {
+    #[default]
+    Remove,
+    ReportOnly,
+}
+
+/// JSON-serializable mirror of [`crate::policy::ImportsNotUsedAsValues`];
+/// plugin options arrive as plain JSON, not Rust enums.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum Policy {
+    #[default]
+    Remove,
+    Preserve,
+    Error,
+}
+
+impl From<Policy> for ImportsNotUsedAsValues {
+    fn from(policy: Policy) -> Self {
+        match policy {
+            Policy::Remove => ImportsNotUsedAsValues::Remove,
+            Policy::Preserve => ImportsNotUsedAsValues::Preserve,
+            Policy::Error => ImportsNotUsedAsValues::Error,
+        }
+    }
+}
+
+/// Options passed down from the host toolchain (`@swc/core`, Next.js SWC,
+/// ...) as the plugin's JSON config.
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AppConfig {
+    #[serde(default)]
+    mode: Mode,
+    #[serde(default)]
+    policy: Policy,
+    /// Module paths (exact matches) whose imports are never removed.
+    #[serde(default)]
+    preserve: Vec<String>,
+    /// Regex patterns matched against module paths for the same purpose.
+    #[serde(default)]
+    preserve_patterns: Vec<String>,
+    /// Forwarded to [`classify`] as `erases_types`; see its doc comment for
+    /// what this flag controls and why it defaults to `false`.
+    #[serde(default)]
+    erases_types: bool,
+}
+
+impl AppConfig {
+    /// Builds the allowlist from `preserve`/`preserve_patterns`, reporting
+    /// (rather than silently dropping) any pattern that isn't a valid regex.
+    fn allowlist(&self) -> PreserveAllowlist {
+        let mut rules: Vec<PreserveRule> = self
+            .preserve
+            .iter()
+            .cloned()
+            .map(PreserveRule::Exact)
+            .collect();
+        rules.extend(self.preserve_patterns.iter().filter_map(|pattern| {
+            match regex::Regex::new(pattern) {
+                Ok(regex) => Some(PreserveRule::Pattern(regex)),
+                Err(err) => {
+                    HANDLER.with(|handler| {
+                        handler.warn(&format!(
+                            "preservePatterns entry `{pattern}` is not a valid regex, ignoring it: {err}"
+                        ));
+                    });
+                    None
+                }
+            }
+        }));
+        PreserveAllowlist::new(rules)
+    }
+}
+
+/// Entry point SWC invokes when this crate is loaded as a wasm plugin, e.g.
+/// from `@swc/core` or Next.js's SWC pipeline.
+///
+/// This runs on an already-parsed `Program` handed to us by the host
+/// toolchain, so unlike [`crate::parse_js`] there's no `Syntax` to build
+/// from plugin metadata here -- TSX/decorator parsing already happened
+/// upstream of this plugin.
+#[plugin_transform]
+pub fn process_transform(program: Program, metadata: TransformPluginProgramMetadata) -> Program {
+    let config: AppConfig = match metadata.get_transform_plugin_config() {
+        Some(raw) => match serde_json::from_str(&raw) {
+            Ok(config) => config,
+            Err(err) => {
+                HANDLER.with(|handler| {
+                    handler.warn(&format!(
+                        "failed to parse plugin config, falling back to defaults: {err}"
+                    ));
+                });
+                AppConfig::default()
+            }
+        },
+        None => AppConfig::default(),
+    };
+
+    let mut import_collector = ImportCollector::new();
+    program.visit_with(&mut import_collector);
+
+    let mut identifier_collector = IdentifierCollector::new();
+    program.visit_with(&mut identifier_collector);
+
+    let allowlist = config.allowlist();
+    let policy = ImportsNotUsedAsValues::from(config.policy);
+
+    let mut unused = HashSet::new();
+    for (local, binding) in &import_collector.imports {
+        if allowlist.is_preserved(&binding.source) {
+            continue;
+        }
+        let value_used = identifier_collector.value_identifiers().contains(local);
+        let type_used = identifier_collector.type_identifiers().contains(local);
+        match classify(
+            binding.type_only,
+            value_used,
+            type_used,
+            policy,
+            config.erases_types,
+        ) {
+            ImportStatus::Unused => {
+                if matches!(config.mode, Mode::ReportOnly) {
+                    HANDLER.with(|handler| {
+                        handler.span_warn(
+                            binding.span,
+                            &format!("`{local}` is imported but never used"),
+                        );
+                    });
+                }
+                unused.insert(local.clone());
+            }
+            ImportStatus::ShouldBeTypeOnly => {
+                HANDLER.with(|handler| {
+                    handler.span_warn(
+                        binding.span,
+                        &format!(
+                            "`{local}` is only used as a type; write it as `import type {{ {local} }}`"
+                        ),
+                    );
+                });
+            }
+            ImportStatus::Used => {}
+        }
+    }
+
+    match config.mode {
+        Mode::ReportOnly => program,
+        Mode::Remove => {
+            let mut program = program;
+            program.visit_mut_with(&mut unused_import_remover(unused, allowlist));
+            program
+        }
+    }
+}
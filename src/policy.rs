@@ -0,0 +1,116 @@
+/// Mirrors TypeScript's `importsNotUsedAsValues` (and, in spirit,
+/// `verbatimModuleSyntax`): what to do with an import whose binding is
+/// referenced only in type positions and therefore erased at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImportsNotUsedAsValues {
+    /// Drop imports that are only used as types (the default: they vanish
+    /// at runtime anyway, so there's nothing for the emitted code to keep).
+    /// This only happens when [`classify`] is called with `erases_types`;
+    /// otherwise such imports are kept, as under `Preserve`.
+    #[default]
+    Remove,
+    /// Keep type-only-used imports around, even where their type-position
+    /// usages are erased from the output.
+    Preserve,
+    /// Flag `import { Foo }` that is used only as a type: it should be
+    /// written as `import type { Foo }` instead.
+    Error,
+}
+
+/// The outcome of classifying one import binding against how it was
+/// actually used in the module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportStatus {
+    /// Not referenced anywhere that counts; safe to remove.
+    Unused,
+    /// Referenced somewhere that counts; keep it.
+    Used,
+    /// A value import used only in type position, under
+    /// [`ImportsNotUsedAsValues::Error`]: should be rewritten as
+    /// `import type`.
+    ShouldBeTypeOnly,
+}
+
+/// Decides what to do with one import binding given whether it was seen in
+/// value position, in type position, and whether it was declared
+/// type-only (`import type { Foo }` or `import { type Foo }`).
+///
+/// `erases_types` tells `classify` whether something downstream of this
+/// crate will also strip type annotations (and therefore the type-position
+/// reference to this binding) from the emitted output. TypeScript's own
+/// `importsNotUsedAsValues: 'remove'` only elides a value import used solely
+/// as a type when the compiler is also erasing the type annotations that
+/// reference it; this crate doesn't strip type nodes itself
+/// ([`crate::remover`] only ever deletes whole `ImportSpecifier`s), so
+/// deleting such an import while leaving `type X = Foo;` behind would emit
+/// TS that references a binding whose import just vanished. Pass `true`
+/// only when the caller has confirmed a type-stripping pass also runs on
+/// the output; otherwise a value import kept alive solely by a type
+/// position use is reported as `Used` under `Remove`, matching `Preserve`.
+pub fn classify(
+    type_only: bool,
+    value_used: bool,
+    type_used: bool,
+    policy: ImportsNotUsedAsValues,
+    erases_types: bool,
+) -> ImportStatus {
+    if type_only {
+        // A type-only binding can't satisfy a value use: only a type-position
+        // reference keeps it alive.
+        return if type_used {
+            ImportStatus::Used
+        } else {
+            ImportStatus::Unused
+        };
+    }
+
+    match (value_used, type_used) {
+        (true, _) => ImportStatus::Used,
+        (false, false) => ImportStatus::Unused,
+        (false, true) => match policy {
+            ImportsNotUsedAsValues::Remove if erases_types => ImportStatus::Unused,
+            ImportsNotUsedAsValues::Remove => ImportStatus::Used,
+            ImportsNotUsedAsValues::Preserve => ImportStatus::Used,
+            ImportsNotUsedAsValues::Error => ImportStatus::ShouldBeTypeOnly,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remove_keeps_type_only_used_value_import_unless_types_are_erased() {
+        assert_eq!(
+            classify(false, false, true, ImportsNotUsedAsValues::Remove, false),
+            ImportStatus::Used
+        );
+        assert_eq!(
+            classify(false, false, true, ImportsNotUsedAsValues::Remove, true),
+            ImportStatus::Unused
+        );
+    }
+
+    #[test]
+    fn remove_still_drops_wholly_unreferenced_import() {
+        assert_eq!(
+            classify(false, false, false, ImportsNotUsedAsValues::Remove, false),
+            ImportStatus::Unused
+        );
+    }
+
+    #[test]
+    fn value_use_is_always_kept_regardless_of_policy() {
+        for policy in [
+            ImportsNotUsedAsValues::Remove,
+            ImportsNotUsedAsValues::Preserve,
+            ImportsNotUsedAsValues::Error,
+        ] {
+            assert_eq!(
+                classify(false, true, false, policy, true),
+                ImportStatus::Used
+            );
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use regex::Regex;
+
+/// A single rule matching an import's module path.
+pub enum PreserveRule {
+    /// Matches a module path exactly, e.g. `"react"`.
+    Exact(String),
+    /// Matches a module path against a regex, e.g. `^@polyfills/`.
+    Pattern(Regex),
+}
+
+impl PreserveRule {
+    fn matches(&self, source: &str) -> bool {
+        match self {
+            PreserveRule::Exact(expected) => expected == source,
+            PreserveRule::Pattern(regex) => regex.is_match(source),
+        }
+    }
+}
+
+/// Modules whose imports must never be removed even when their bindings
+/// look unused — polyfills, ambient/CSS side-effect packages, or the
+/// classic-runtime `react` import that JSX needs implicitly.
+#[derive(Default)]
+pub struct PreserveAllowlist {
+    rules: Vec<PreserveRule>,
+}
+
+impl PreserveAllowlist {
+    pub fn new(rules: Vec<PreserveRule>) -> Self {
+        Self { rules }
+    }
+
+    pub fn is_preserved(&self, source: &str) -> bool {
+        self.rules.iter().any(|rule| rule.matches(source))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_rule_matches_only_the_full_module_path() {
+        let allowlist = PreserveAllowlist::new(vec![PreserveRule::Exact("react".to_string())]);
+        assert!(allowlist.is_preserved("react"));
+        assert!(!allowlist.is_preserved("react-dom"));
+    }
+
+    #[test]
+    fn pattern_rule_matches_by_regex() {
+        let allowlist = PreserveAllowlist::new(vec![PreserveRule::Pattern(
+            Regex::new("^@polyfills/").unwrap(),
+        )]);
+        assert!(allowlist.is_preserved("@polyfills/fetch"));
+        assert!(!allowlist.is_preserved("@internal/fetch"));
+    }
+
+    #[test]
+    fn empty_allowlist_preserves_nothing() {
+        let allowlist = PreserveAllowlist::default();
+        assert!(!allowlist.is_preserved("react"));
+    }
+
+    #[test]
+    fn any_matching_rule_is_enough() {
+        let allowlist = PreserveAllowlist::new(vec![
+            PreserveRule::Exact("react".to_string()),
+            PreserveRule::Pattern(Regex::new("\\.css$").unwrap()),
+        ]);
+        assert!(allowlist.is_preserved("react"));
+        assert!(allowlist.is_preserved("./styles.css"));
+        assert!(!allowlist.is_preserved("lodash"));
+    }
+}
@@ -0,0 +1,469 @@
+use std::collections::HashSet;
+
+use swc_ecma_ast::{
+    ArrowExpr, BindingIdent, BlockStmt, BlockStmtOrExpr, CatchClause, ClassDecl, Decl, FnDecl,
+    FnExpr, Function, Ident, NewExpr, ObjectPatProp, Pat, Stmt, TaggedTpl, TsEntityName,
+    TsExprWithTypeArgs, TsInterfaceDecl, TsTypeAnn, TsTypeElement, TsTypeRef,
+};
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// Bindings introduced directly within one lexical scope (function params,
+/// a block's own declarations, a catch clause's parameter, ...).
+#[derive(Default)]
+struct Scope {
+    bindings: HashSet<String>,
+}
+
+/// Collects identifiers that are *read* somewhere in the module, resolving
+/// each value-position read against a stack of lexical scopes so that a
+/// local binding (a parameter, a `let`/`const`/`var`, a hoisted function)
+/// shadows a same-named import instead of being mistaken for a use of it.
+///
+/// Value-position and type-position reads are tracked separately: a type
+/// import erased at compile time doesn't satisfy a value use, while how a
+/// value import used only in type position is treated depends on the
+/// policy (see [`crate::policy`]).
+pub struct IdentifierCollector {
+    value_identifiers: HashSet<String>,
+    type_identifiers: HashSet<String>,
+    scopes: Vec<Scope>,
+}
+
+impl IdentifierCollector {
+    pub fn new() -> Self {
+        Self {
+            value_identifiers: HashSet::new(),
+            type_identifiers: HashSet::new(),
+            scopes: vec![Scope::default()],
+        }
+    }
+
+    pub fn value_identifiers(&self) -> &HashSet<String> {
+        &self.value_identifiers
+    }
+
+    pub fn type_identifiers(&self) -> &HashSet<String> {
+        &self.type_identifiers
+    }
+
+    fn push_scope(&mut self) {
+        self.scopes.push(Scope::default());
+    }
+
+    fn pop_scope(&mut self) {
+        self.scopes.pop();
+    }
+
+    fn bind(&mut self, name: String) {
+        self.scopes
+            .last_mut()
+            .expect("IdentifierCollector always has at least one scope")
+            .bindings
+            .insert(name);
+    }
+
+    fn is_bound(&self, name: &str) -> bool {
+        self.scopes.iter().any(|scope| scope.bindings.contains(name))
+    }
+
+    /// Records a value-position read of `name`, unless it resolves to a
+    /// binding introduced by an enclosing scope rather than the
+    /// module-level import.
+    fn record_use(&mut self, name: &str) {
+        if !self.is_bound(name) {
+            self.value_identifiers.insert(name.to_string());
+        }
+    }
+
+    /// Records a type-position read of `name`. Type space has no runtime
+    /// shadowing to worry about (generics/local type aliases aren't
+    /// modeled here), so every reference is recorded as-is.
+    fn record_type_use(&mut self, name: &str) {
+        self.type_identifiers.insert(name.to_string());
+    }
+
+    /// Pre-binds the function, class and variable (`var`/`let`/`const`)
+    /// declarations directly inside `stmts`, so that a reference anywhere in
+    /// the block, even one before the declaration, resolves to them.
+    fn hoist(&mut self, stmts: &[Stmt]) {
+        for stmt in stmts {
+            match stmt {
+                Stmt::Decl(Decl::Fn(fn_decl)) => self.bind(fn_decl.ident.sym.to_string()),
+                Stmt::Decl(Decl::Class(class_decl)) => {
+                    self.bind(class_decl.ident.sym.to_string())
+                }
+                Stmt::Decl(Decl::Var(var_decl)) => {
+                    for declarator in &var_decl.decls {
+                        bind_pat_names(self, &declarator.name);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Default for IdentifierCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn bind_pat_names(collector: &mut IdentifierCollector, pat: &Pat) {
+    match pat {
+        Pat::Ident(binding) => collector.bind(binding.id.sym.to_string()),
+        Pat::Array(array) => {
+            for elem in array.elems.iter().flatten() {
+                bind_pat_names(collector, elem);
+            }
+        }
+        Pat::Object(object) => {
+            for prop in &object.props {
+                match prop {
+                    ObjectPatProp::KeyValue(kv) => bind_pat_names(collector, &kv.value),
+                    ObjectPatProp::Assign(assign) => {
+                        collector.bind(assign.key.id.sym.to_string())
+                    }
+                    ObjectPatProp::Rest(rest) => bind_pat_names(collector, &rest.arg),
+                }
+            }
+        }
+        Pat::Rest(rest) => bind_pat_names(collector, &rest.arg),
+        Pat::Assign(assign) => bind_pat_names(collector, &assign.left),
+        Pat::Expr(_) | Pat::Invalid(_) => {}
+    }
+}
+
+impl Visit for IdentifierCollector {
+    fn visit_ident(&mut self, ident: &Ident) {
+        self.record_use(&ident.sym);
+    }
+
+    fn visit_binding_ident(&mut self, binding: &BindingIdent) {
+        // A binding occurrence (parameter, declarator name, ...) introduces
+        // a name rather than reading one.
+        self.bind(binding.id.sym.to_string());
+        if let Some(type_ann) = &binding.type_ann {
+            self.visit_ts_type_ann(type_ann);
+        }
+    }
+
+    fn visit_fn_decl(&mut self, fn_decl: &FnDecl) {
+        // `fn_decl.ident` is the declaration's own name, not a usage.
+        fn_decl.function.visit_with(self);
+    }
+
+    fn visit_fn_expr(&mut self, fn_expr: &FnExpr) {
+        // Same as above; a named function expression's name is only in
+        // scope inside its own body, which we don't model here.
+        fn_expr.function.visit_with(self);
+    }
+
+    fn visit_class_decl(&mut self, class_decl: &ClassDecl) {
+        self.bind(class_decl.ident.sym.to_string());
+        class_decl.class.visit_with(self);
+    }
+
+    fn visit_function(&mut self, function: &Function) {
+        self.push_scope();
+        for param in &function.params {
+            // Bind every name the parameter pattern introduces before
+            // descending into it, so a destructured name that shadows an
+            // import (`{ format }`) resolves as a binding rather than a
+            // read, however deep inside the pattern it appears.
+            bind_pat_names(self, &param.pat);
+            param.visit_with(self);
+        }
+        if let Some(body) = &function.body {
+            body.visit_with(self);
+        }
+        if let Some(return_type) = &function.return_type {
+            self.visit_ts_type_ann(return_type);
+        }
+        self.pop_scope();
+    }
+
+    fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+        self.push_scope();
+        for param in &arrow.params {
+            bind_pat_names(self, param);
+            param.visit_with(self);
+        }
+        match &*arrow.body {
+            BlockStmtOrExpr::BlockStmt(block) => block.visit_with(self),
+            BlockStmtOrExpr::Expr(expr) => expr.visit_with(self),
+        }
+        self.pop_scope();
+    }
+
+    fn visit_block_stmt(&mut self, block: &BlockStmt) {
+        self.push_scope();
+        self.hoist(&block.stmts);
+        for stmt in &block.stmts {
+            stmt.visit_with(self);
+        }
+        self.pop_scope();
+    }
+
+    fn visit_catch_clause(&mut self, catch: &CatchClause) {
+        self.push_scope();
+        if let Some(param) = &catch.param {
+            bind_pat_names(self, param);
+            param.visit_with(self);
+        }
+        catch.body.visit_with(self);
+        self.pop_scope();
+    }
+
+    fn visit_new_expr(&mut self, new_expr: &NewExpr) {
+        if let swc_ecma_ast::Expr::Ident(ident) = &*new_expr.callee {
+            self.record_use(&ident.sym);
+        }
+        if let Some(args) = &new_expr.args {
+            for arg in args {
+                arg.visit_with(self);
+            }
+        }
+    }
+
+    fn visit_import_decl(&mut self, _import: &swc_ecma_ast::ImportDecl) {
+        // Import specifiers are bindings, not reads; `ImportCollector`
+        // handles them separately.
+    }
+
+    fn visit_ts_type_ref(&mut self, type_ref: &TsTypeRef) {
+        match &type_ref.type_name {
+            TsEntityName::Ident(ident) => {
+                self.record_type_use(&ident.sym);
+            }
+            TsEntityName::TsQualifiedName(qual) => {
+                // Handle cases like Types.SomeInterface
+                if let TsEntityName::Ident(left) = &qual.left {
+                    self.record_type_use(&left.sym);
+                }
+                self.record_type_use(&qual.right.sym);
+            }
+        }
+        // Recurse into the type's own generic arguments (`Array<Foo>`,
+        // `Promise<Foo>`, ...) instead of stopping at the head identifier.
+        if let Some(type_params) = &type_ref.type_params {
+            type_params.visit_with(self);
+        }
+    }
+
+    fn visit_ts_type_ann(&mut self, type_ann: &TsTypeAnn) {
+        // Dispatch generically instead of special-casing `TsTypeRef`, so
+        // array (`Foo[]`), union/intersection (`Foo | null`), and other
+        // compound type shapes still reach the type refs nested inside
+        // them.
+        type_ann.type_ann.visit_with(self);
+    }
+
+    fn visit_ts_interface_decl(&mut self, interface: &TsInterfaceDecl) {
+        // Visit extends clause
+        for extend in &interface.extends {
+            self.visit_ts_expr_with_type_args(extend);
+        }
+        // Visit each member of the interface body
+        for member in &interface.body.body {
+            let type_ann = match member {
+                TsTypeElement::TsCallSignatureDecl(sig) => sig.type_ann.as_deref(),
+                TsTypeElement::TsConstructSignatureDecl(sig) => sig.type_ann.as_deref(),
+                TsTypeElement::TsPropertySignature(prop) => prop.type_ann.as_deref(),
+                TsTypeElement::TsGetterSignature(getter) => getter.type_ann.as_deref(),
+                TsTypeElement::TsSetterSignature(_) => None,
+                TsTypeElement::TsMethodSignature(method) => method.type_ann.as_deref(),
+                TsTypeElement::TsIndexSignature(index) => index.type_ann.as_deref(),
+            };
+            if let Some(type_ann) = type_ann {
+                self.visit_ts_type_ann(type_ann);
+            }
+        }
+    }
+
+    fn visit_ts_expr_with_type_args(&mut self, type_args: &TsExprWithTypeArgs) {
+        if let swc_ecma_ast::Expr::Ident(ref ident) = *type_args.expr {
+            self.record_type_use(&ident.sym);
+        }
+    }
+
+    fn visit_tagged_tpl(&mut self, tpl: &TaggedTpl) {
+        // Handle styled-components and emotion template literals
+        if let swc_ecma_ast::Expr::Member(member) = &*tpl.tag {
+            if let swc_ecma_ast::Expr::Ident(obj) = &*member.obj {
+                // Record the base identifier (e.g., 'styled' in styled.div)
+                self.record_use(&obj.sym);
+            }
+        } else if let swc_ecma_ast::Expr::Ident(ident) = &*tpl.tag {
+            // Record direct identifier usage (e.g., css`...`)
+            self.record_use(&ident.sym);
+        }
+        // Keep descending into the template's interpolated expressions, so
+        // a tagged template nested inside another one's `${...}` (e.g.
+        // `css` used inside a `styled.div` interpolation) is still found.
+        tpl.tpl.visit_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_js;
+
+    fn used(code: &str) -> HashSet<String> {
+        let (_, module) = parse_js(code);
+        let mut collector = IdentifierCollector::new();
+        collector.visit_module(&module);
+        collector.value_identifiers().clone()
+    }
+
+    #[test]
+    fn parameter_shadows_import() {
+        let used = used(
+            r#"
+            import { format } from 'date-fns';
+
+            function render(format) {
+                return format;
+            }
+            "#,
+        );
+        assert!(!used.contains("format"));
+    }
+
+    #[test]
+    fn block_scoped_redeclaration_shadows_import() {
+        let used = used(
+            r#"
+            import { format } from 'date-fns';
+
+            function render() {
+                {
+                    const format = 'yyyy-MM-dd';
+                    console.log(format);
+                }
+            }
+            "#,
+        );
+        assert!(!used.contains("format"));
+    }
+
+    #[test]
+    fn hoisted_function_name_shadows_import() {
+        let used = used(
+            r#"
+            import { helper } from './helper';
+
+            function outer() {
+                console.log(helper());
+
+                function helper() {
+                    return 42;
+                }
+            }
+            "#,
+        );
+        assert!(!used.contains("helper"));
+    }
+
+    #[test]
+    fn object_destructured_parameter_shadows_import() {
+        let used = used(
+            r#"
+            import { format } from 'date-fns';
+
+            function render({ format }) {
+                return format;
+            }
+            "#,
+        );
+        assert!(!used.contains("format"));
+    }
+
+    #[test]
+    fn destructuring_rename_is_not_a_value_use() {
+        let used = used(
+            r#"
+            import { format } from 'date-fns';
+
+            const { format: myDate } = obj;
+            "#,
+        );
+        assert!(!used.contains("format"));
+    }
+
+    fn type_used(code: &str) -> HashSet<String> {
+        let (_, module) = parse_js(code);
+        let mut collector = IdentifierCollector::new();
+        collector.visit_module(&module);
+        collector.type_identifiers().clone()
+    }
+
+    #[test]
+    fn generic_type_argument_is_a_type_use() {
+        let used = type_used(
+            r#"
+            import { Foo } from './foo';
+
+            declare function f(): Promise<Foo>;
+            "#,
+        );
+        assert!(used.contains("Foo"));
+    }
+
+    #[test]
+    fn array_element_type_is_a_type_use() {
+        let used = type_used(
+            r#"
+            import { Foo } from './foo';
+
+            declare const w: Foo[];
+            "#,
+        );
+        assert!(used.contains("Foo"));
+    }
+
+    #[test]
+    fn union_member_type_is_a_type_use() {
+        let used = type_used(
+            r#"
+            import { Foo } from './foo';
+
+            declare const u: Foo | null;
+            "#,
+        );
+        assert!(used.contains("Foo"));
+    }
+
+    #[test]
+    fn nested_tagged_template_inside_an_interpolation_is_still_recorded() {
+        let used = used(
+            r#"
+            import { styled } from '@emotion/styled';
+            import { css } from '@emotion/styled';
+
+            const StyledDiv = styled.div`
+                ${props => css`
+                    background: ${props.theme.background};
+                `}
+            `;
+            "#,
+        );
+        assert!(used.contains("styled"));
+        assert!(used.contains("css"));
+    }
+
+    #[test]
+    fn unshadowed_import_is_still_used() {
+        let used = used(
+            r#"
+            import { format } from 'date-fns';
+
+            function render() {
+                return format(new Date(), 'yyyy-MM-dd');
+            }
+            "#,
+        );
+        assert!(used.contains("format"));
+    }
+}